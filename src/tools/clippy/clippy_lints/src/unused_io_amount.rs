@@ -1,13 +1,17 @@
 use crate::utils::{is_try, match_qpath, match_trait_method, paths, span_lint};
 use rustc_hir as hir;
+use rustc_hir::intravisit::{walk_expr, walk_path, walk_stmt, NestedVisitorMap, Visitor};
 use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::hir::map::Map;
 use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::Span;
 
 declare_clippy_lint! {
     /// **What it does:** Checks for unused written/read amount.
     ///
     /// **Why is this bad?** `io::Write::write(_vectored)` and
-    /// `io::Read::read(_vectored)` are not guaranteed to
+    /// `io::Read::read(_vectored)`, as well as their `futures::io`/`tokio::io`
+    /// `AsyncWriteExt`/`AsyncReadExt` counterparts, are not guaranteed to
     /// process the entire buffer. They return how many bytes were processed, which
     /// might be smaller
     /// than a given buffer's length. If you don't need to deal with
@@ -40,21 +44,19 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for UnusedIoAmount {
         };
 
         match expr.kind {
-            hir::ExprKind::Match(ref res, _, _) if is_try(expr).is_some() => {
-                if let hir::ExprKind::Call(ref func, ref args) = res.kind {
-                    if let hir::ExprKind::Path(ref path) = func.kind {
-                        if match_qpath(path, &paths::TRY_INTO_RESULT) && args.len() == 1 {
-                            check_method_call(cx, &args[0], expr);
-                        }
-                    }
-                } else {
-                    check_method_call(cx, res, expr);
+            hir::ExprKind::Match(..) if is_try(expr).is_some() => {
+                if let Some(call) = unwrap_try(expr) {
+                    check_method_call(cx, call, expr.span);
                 }
             },
 
+            // A bare `w.write(buf).await;` / `r.read(buf).await;` statement, with no `?` or
+            // `unwrap` around the `.await`.
+            hir::ExprKind::Await(ref future) => check_method_call(cx, future, expr.span),
+
             hir::ExprKind::MethodCall(ref path, _, ref args) => match &*path.ident.as_str() {
                 "expect" | "unwrap" | "unwrap_or" | "unwrap_or_else" => {
-                    check_method_call(cx, &args[0], expr);
+                    check_method_call(cx, &args[0], expr.span);
                 },
                 _ => (),
             },
@@ -62,29 +64,143 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for UnusedIoAmount {
             _ => (),
         }
     }
+
+    fn check_block(&mut self, cx: &LateContext<'a, 'tcx>, block: &'tcx hir::Block<'tcx>) {
+        for (i, stmt) in block.stmts.iter().enumerate() {
+            let local = match stmt.kind {
+                hir::StmtKind::Local(ref local) => local,
+                _ => continue,
+            };
+            let init = match local.init {
+                Some(init) => init,
+                None => continue,
+            };
+
+            let is_ignored = match local.pat.kind {
+                hir::PatKind::Wild => true,
+                hir::PatKind::Binding(_, hir_id, ident, None) if ident.as_str().starts_with('_') => {
+                    !is_local_used(cx, hir_id, &block.stmts[i + 1..], block.expr)
+                },
+                _ => false,
+            };
+            if !is_ignored {
+                continue;
+            }
+
+            // `let _ = w.write(buf)?;` produces the identical `Match`-over-`Call` shape around the
+            // real method call that a bare `w.write(buf)?;` statement does.
+            let call = match init.kind {
+                hir::ExprKind::Match(..) if is_try(init).is_some() => unwrap_try(init),
+                _ => Some(init),
+            };
+
+            if let Some(call) = call {
+                check_method_call(cx, call, local.span);
+            }
+        }
+    }
 }
 
-fn check_method_call(cx: &LateContext<'_, '_>, call: &hir::Expr<'_>, expr: &hir::Expr<'_>) {
+/// If `expr` is the `?` (`Try`) desugaring around a method call, returns the inner call
+/// expression to check; shared by `check_stmt` and `check_block` since `let _ = w.write(buf)?;`
+/// and `w.write(buf)?;` desugar to the identical `Match`-over-`Call` shape.
+fn unwrap_try<'tcx>(expr: &'tcx hir::Expr<'tcx>) -> Option<&'tcx hir::Expr<'tcx>> {
+    let res = match expr.kind {
+        hir::ExprKind::Match(ref res, _, _) => res,
+        _ => return None,
+    };
+
+    if let hir::ExprKind::Call(ref func, ref args) = res.kind {
+        if let hir::ExprKind::Path(ref path) = func.kind {
+            if match_qpath(path, &paths::TRY_INTO_RESULT) && args.len() == 1 {
+                return Some(&args[0]);
+            }
+        }
+        None
+    } else {
+        Some(res)
+    }
+}
+
+/// Checks whether the local bound to `hir_id` is read anywhere among `stmts`/`tail`, i.e. the
+/// rest of the enclosing block after its `let`. Descends into nested closure and `async` block
+/// bodies (via `NestedVisitorMap::All`) since those commonly capture the binding by name, e.g.
+/// `async move { use(_n) }`.
+fn is_local_used<'tcx>(
+    cx: &LateContext<'_, 'tcx>,
+    hir_id: hir::HirId,
+    stmts: &'tcx [hir::Stmt<'tcx>],
+    tail: Option<&'tcx hir::Expr<'tcx>>,
+) -> bool {
+    struct LocalUseVisitor<'tcx> {
+        hir_id: hir::HirId,
+        used: bool,
+        map: Map<'tcx>,
+    }
+
+    impl<'tcx> Visitor<'tcx> for LocalUseVisitor<'tcx> {
+        type Map = Map<'tcx>;
+
+        fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+            NestedVisitorMap::All(self.map)
+        }
+
+        fn visit_path(&mut self, path: &'tcx hir::Path<'tcx>, _: hir::HirId) {
+            if let hir::def::Res::Local(id) = path.res {
+                if id == self.hir_id {
+                    self.used = true;
+                }
+            }
+            walk_path(self, path);
+        }
+    }
+
+    let mut visitor = LocalUseVisitor {
+        hir_id,
+        used: false,
+        map: cx.tcx.hir(),
+    };
+    for stmt in stmts {
+        walk_stmt(&mut visitor, stmt);
+    }
+    if let Some(expr) = tail {
+        walk_expr(&mut visitor, expr);
+    }
+    visitor.used
+}
+
+fn check_method_call(cx: &LateContext<'_, '_>, call: &hir::Expr<'_>, span: Span) {
+    // `foo(..).await` surfaces here as `Await(MethodCall(..))`; peel it so the async
+    // `AsyncReadExt`/`AsyncWriteExt` methods are matched the same way as their sync counterparts.
+    let call = match call.kind {
+        hir::ExprKind::Await(ref future) => future,
+        _ => call,
+    };
+
     if let hir::ExprKind::MethodCall(ref path, _, _) = call.kind {
         let symbol = &*path.ident.as_str();
-        let read_trait = match_trait_method(cx, call, &paths::IO_READ);
-        let write_trait = match_trait_method(cx, call, &paths::IO_WRITE);
+        let read_trait = match_trait_method(cx, call, &paths::IO_READ)
+            || match_trait_method(cx, call, &paths::FUTURES_IO_ASYNCREADEXT)
+            || match_trait_method(cx, call, &paths::TOKIO_IO_ASYNCREADEXT);
+        let write_trait = match_trait_method(cx, call, &paths::IO_WRITE)
+            || match_trait_method(cx, call, &paths::FUTURES_IO_ASYNCWRITEEXT)
+            || match_trait_method(cx, call, &paths::TOKIO_IO_ASYNCWRITEEXT);
 
         match (read_trait, write_trait, symbol) {
             (true, _, "read") => span_lint(
                 cx,
                 UNUSED_IO_AMOUNT,
-                expr.span,
+                span,
                 "read amount is not handled. Use `Read::read_exact` instead",
             ),
-            (true, _, "read_vectored") => span_lint(cx, UNUSED_IO_AMOUNT, expr.span, "read amount is not handled"),
+            (true, _, "read_vectored") => span_lint(cx, UNUSED_IO_AMOUNT, span, "read amount is not handled"),
             (_, true, "write") => span_lint(
                 cx,
                 UNUSED_IO_AMOUNT,
-                expr.span,
+                span,
                 "written amount is not handled. Use `Write::write_all` instead",
             ),
-            (_, true, "write_vectored") => span_lint(cx, UNUSED_IO_AMOUNT, expr.span, "written amount is not handled"),
+            (_, true, "write_vectored") => span_lint(cx, UNUSED_IO_AMOUNT, span, "written amount is not handled"),
             _ => (),
         }
     }
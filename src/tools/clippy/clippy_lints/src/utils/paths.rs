@@ -0,0 +1,20 @@
+//! This file contains the paths of functions, types and traits that Clippy matches on.
+//!
+//! Paths are used to check if a `def_id` matches a specific function, type, or trait. They are
+//! compared with the item's definition path, so an entry names the module that defines the item,
+//! not a module that only re-exports it.
+//! Add new entries here rather than matching on string-formatted paths in the lints themselves.
+
+pub const TRY_INTO_RESULT: [&str; 4] = ["std", "ops", "Try", "into_result"];
+pub const IO_READ: [&str; 3] = ["std", "io", "Read"];
+pub const IO_WRITE: [&str; 3] = ["std", "io", "Write"];
+
+// `AsyncReadExt`/`AsyncWriteExt` are defined directly in `futures_util::io`'s `mod.rs`, so the
+// facade path is also their real def path.
+pub const FUTURES_IO_ASYNCREADEXT: [&str; 3] = ["futures_util", "io", "AsyncReadExt"];
+pub const FUTURES_IO_ASYNCWRITEEXT: [&str; 3] = ["futures_util", "io", "AsyncWriteExt"];
+
+// Unlike futures, tokio's `AsyncReadExt`/`AsyncWriteExt` are defined in their own modules under
+// `tokio::io::util`, not in the `tokio::io` facade that re-exports them.
+pub const TOKIO_IO_ASYNCREADEXT: [&str; 5] = ["tokio", "io", "util", "async_read_ext", "AsyncReadExt"];
+pub const TOKIO_IO_ASYNCWRITEEXT: [&str; 5] = ["tokio", "io", "util", "async_write_ext", "AsyncWriteExt"];
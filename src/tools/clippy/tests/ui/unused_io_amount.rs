@@ -0,0 +1,63 @@
+#![warn(clippy::unused_io_amount)]
+
+extern crate futures_util;
+extern crate tokio;
+
+use futures_util::io::{AsyncReadExt as _, AsyncWriteExt as _};
+use std::io::{self, Read, Write};
+use tokio::io::{AsyncReadExt as _, AsyncWriteExt as _};
+
+fn try_sync<T: Read + Write>(s: &mut T) -> io::Result<()> {
+    s.write(b"test")?;
+    s.read(&mut [0; 4])?;
+    Ok(())
+}
+
+fn unwrap_sync<T: Read + Write>(s: &mut T) {
+    s.write(b"test").unwrap();
+    s.read(&mut [0; 4]).unwrap();
+}
+
+async fn try_async<T: futures_util::io::AsyncRead + futures_util::io::AsyncWrite + Unpin>(
+    s: &mut T,
+) -> io::Result<()> {
+    s.write(b"test").await?;
+    s.read(&mut [0; 4]).await?;
+    Ok(())
+}
+
+async fn unwrap_async<T: tokio::io::AsyncRead + tokio::io::AsyncWrite + Unpin>(s: &mut T) {
+    s.write(b"test").await.unwrap();
+    s.read(&mut [0; 4]).await.unwrap();
+}
+
+fn ignored_binding<T: Read + Write>(s: &mut T) -> io::Result<()> {
+    let _ = s.write(b"test")?;
+    let _n = s.read(&mut [0; 4])?;
+    Ok(())
+}
+
+fn binding_is_read<T: Read + Write>(s: &mut T) -> io::Result<()> {
+    // Not a bug: `_n` is read below, so the amount is handled.
+    let _n = s.read(&mut [0; 4])?;
+    println!("{}", _n);
+    Ok(())
+}
+
+fn binding_captured_by_closure<T: Read + Write>(s: &mut T) -> io::Result<()> {
+    // Not a bug: `_n` is only read from within the closure.
+    let _n = s.read(&mut [0; 4])?;
+    let read = move || println!("{}", _n);
+    read();
+    Ok(())
+}
+
+async fn binding_captured_by_async_block<T: futures_util::io::AsyncRead + Unpin>(s: &mut T) -> io::Result<()> {
+    // Not a bug: `_n` is only read from within the `async` block.
+    let _n = s.read(&mut [0; 4]).await?;
+    let fut = async move { println!("{}", _n) };
+    fut.await;
+    Ok(())
+}
+
+fn main() {}